@@ -71,81 +71,1178 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Async
+//!
+//! Enabling the `futures` feature switches the per-subscriber channel to
+//! `futures_channel::mpsc`, so `Receiver` implements `futures::Stream` and
+//! `Sender` implements `futures::Sink`, on top of the same topic
+//! subscriptions, resilient broadcast and bounded queues
+//! (`new_bounded`/`publish`/`subscribe`) as the default backend, while
+//! `send`/`recv`/`try_recv` keep working as before.
+//!
+//! # Crossbeam
+//!
+//! Enabling the `crossbeam` feature switches the per-subscriber channel to
+//! `crossbeam_channel`, adding `Receiver::select_handle`, which exposes the
+//! underlying `crossbeam_channel::Receiver` so it can be registered in a
+//! `crossbeam_channel::Select` alongside other channels. Everything else
+//! (`send`/`recv`/`try_recv`/`publish`/`subscribe`) works as in the default
+//! backend.
+//!
+//! The `futures` and `crossbeam` features are mutually exclusive: enabling
+//! both is a compile error, since a consumer who enabled `crossbeam` for
+//! `select_handle` would otherwise silently lose it to the `futures`
+//! backend.
+
+#[cfg(all(feature = "futures", feature = "crossbeam"))]
+compile_error!("the `futures` and `crossbeam` features cannot be enabled together");
 
+#[allow(unused_imports)]
 #[macro_use]
 extern crate log;
 extern crate uuid;
+#[cfg(feature = "futures")]
+extern crate futures_channel;
+#[cfg(feature = "futures")]
+extern crate futures_core;
+#[cfg(feature = "futures")]
+extern crate futures_sink;
+#[cfg(feature = "futures")]
+extern crate futures_executor;
+#[cfg(feature = "futures")]
+extern crate futures_util;
 
-use std::sync::{mpsc, Arc, Mutex};
+#[cfg(not(all(feature = "crossbeam", not(feature = "futures"))))]
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
+use std::collections::HashSet;
+#[cfg(not(feature = "futures"))]
+use std::time::Duration;
+#[cfg(not(feature = "futures"))]
+use std::time::Instant;
+
+// --- shared subscription/broadcast bookkeeping --------------------------
+//
+// The default, `futures` and `crossbeam` backends each hand subscribers a
+// different kind of channel sender, but the bookkeeping around it (topic
+// subscriptions, pruning a disconnected subscriber instead of aborting a
+// broadcast, keeping the shared subscriber map locked for as little time
+// as possible) is identical. That logic lives here, written once, generic
+// over the per-backend sender type `S` via [`SubscriberSender`]; only the
+// channel-specific `recv`/`try_recv`/`Stream`/`Sink` implementations
+// remain per-backend, since those genuinely differ.
+
+/// A subscriber's interest in published messages.
+///
+/// The default, used until [`Receiver::subscribe`]/[`Receiver::subscribe_with`]
+/// narrows it, is [`Subscription::All`], which matches every topic so a
+/// fresh subscriber behaves like the original broadcast-to-everyone design.
+pub enum Subscription<T> {
+    /// Matches every topic.
+    All,
+    /// Matches messages published under one of these topics.
+    Topics(HashSet<String>),
+    /// Matches messages for which the predicate returns `true`.
+    Predicate(Box<dyn Fn(&T) -> bool + Send>),
+}
+
+impl<T> Subscription<T> {
+    fn matches(&self, topic: &str, it: &T) -> bool {
+        match *self {
+            Subscription::All => true,
+            Subscription::Topics(ref topics) => topics.contains(topic),
+            Subscription::Predicate(ref predicate) => predicate(it),
+        }
+    }
+}
+
+/// Summary of a broadcast: how many subscribers received the message, and
+/// which ones were found disconnected and pruned along the way.
+#[derive(Debug, Clone)]
+pub struct BroadcastOutcome {
+    /// Number of subscribers the message was delivered to.
+    pub delivered: usize,
+    /// Subscribers that were disconnected and have been removed.
+    pub dropped: Vec<uuid::Uuid>,
+}
+
+/// A subscriber's sending half together with its current subscription,
+/// generic over the backend's channel-sender type `S`.
+struct Subscriber<S, T> {
+    sender: S,
+    subscription: Subscription<T>,
+}
+
+/// A per-subscriber channel-sender handle, abstracted over the backend's
+/// concrete channel type so the bookkeeping in this module only has to be
+/// written once. Implementors are cheap to `clone()`: cloning yields
+/// another handle to the same underlying channel, not a new channel.
+trait SubscriberSender<T>: Clone {
+    /// Error reported by a blocking (or awaited) send.
+    type SendError;
+    /// Error reported by a non-blocking send.
+    type TrySendError;
+
+    /// Sends a message, blocking if the subscriber's bounded queue is full.
+    fn send(&mut self, it: T) -> Result<(), Self::SendError>;
+
+    /// Sends a message without blocking, reporting a full or disconnected
+    /// queue instead of waiting.
+    fn try_send(&mut self, it: T) -> Result<(), Self::TrySendError>;
+
+    /// Reports whether a `try_send` error means the subscriber is gone, as
+    /// opposed to merely full.
+    fn is_disconnected(err: &Self::TrySendError) -> bool;
+}
+
+/// Broadcasts a message to every subscriber in `senders`, ignoring
+/// subscriptions. A disconnected subscriber is pruned and delivery
+/// continues to everyone else, rather than aborting on the first failure.
+/// Blocks on any subscriber whose bounded queue is currently full, but
+/// only that subscriber: `senders` is not held locked while waiting, so
+/// other subscribers still receive the message and concurrent `Receiver`
+/// housekeeping (subscribe/clone/drop) is not stalled by it.
+fn broadcast_to<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, it: T) -> BroadcastOutcome
+    where S: SubscriberSender<T>, T: Clone
+{
+    let targets: Vec<(uuid::Uuid, S)> = {
+        let senders = senders.lock().unwrap();
+        senders.iter().map(|(id, subscriber)| (*id, subscriber.sender.clone())).collect()
+    };
+
+    let mut outcome = BroadcastOutcome { delivered: 0, dropped: Vec::new() };
+    for (id, mut sender) in targets {
+        match sender.send(it.clone()) {
+            Ok(_) => outcome.delivered += 1,
+            Err(_) => outcome.dropped.push(id),
+        }
+    }
+
+    if !outcome.dropped.is_empty() {
+        let mut senders = senders.lock().unwrap();
+        for id in &outcome.dropped {
+            senders.remove(id);
+        }
+    }
+
+    outcome
+}
+
+/// Broadcasts a message to every subscriber in `senders` without blocking,
+/// ignoring subscriptions, pruning any subscriber found disconnected (a
+/// full one is reported but kept), and reporting each subscriber's outcome
+/// individually instead of bailing out on the first failure.
+fn try_send_to<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, it: T)
+    -> Vec<(uuid::Uuid, Result<(), S::TrySendError>)>
+    where S: SubscriberSender<T>, T: Clone
+{
+    let targets: Vec<(uuid::Uuid, S)> = {
+        let senders = senders.lock().unwrap();
+        senders.iter().map(|(id, subscriber)| (*id, subscriber.sender.clone())).collect()
+    };
+
+    let mut results = Vec::with_capacity(targets.len());
+    let mut disconnected = Vec::new();
+    for (id, mut sender) in targets {
+        let result = sender.try_send(it.clone());
+        if let Err(ref err) = result {
+            if S::is_disconnected(err) {
+                disconnected.push(id);
+            }
+        }
+        results.push((id, result));
+    }
+
+    if !disconnected.is_empty() {
+        let mut senders = senders.lock().unwrap();
+        for id in &disconnected {
+            senders.remove(id);
+        }
+    }
+
+    results
+}
+
+/// Delivers a message to subscribers in `senders` whose subscription
+/// matches `topic`. A disconnected matching subscriber is pruned and
+/// delivery continues to everyone else. Blocks on any matching subscriber
+/// whose bounded queue is currently full, but, as with [`broadcast_to`],
+/// only that subscriber.
+fn publish_to<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, topic: &str, it: T) -> BroadcastOutcome
+    where S: SubscriberSender<T>, T: Clone
+{
+    let targets: Vec<(uuid::Uuid, S)> = {
+        let senders = senders.lock().unwrap();
+        senders.iter()
+            .filter(|(_, subscriber)| subscriber.subscription.matches(topic, &it))
+            .map(|(id, subscriber)| (*id, subscriber.sender.clone()))
+            .collect()
+    };
 
+    let mut outcome = BroadcastOutcome { delivered: 0, dropped: Vec::new() };
+    for (id, mut sender) in targets {
+        match sender.send(it.clone()) {
+            Ok(_) => outcome.delivered += 1,
+            Err(_) => outcome.dropped.push(id),
+        }
+    }
+
+    if !outcome.dropped.is_empty() {
+        let mut senders = senders.lock().unwrap();
+        for id in &outcome.dropped {
+            senders.remove(id);
+        }
+    }
+
+    outcome
+}
+
+/// Subscribes `id` to `topic`, in addition to any topics already
+/// subscribed to. Switches the subscription away from [`Subscription::All`]
+/// (or a predicate) to an explicit topic set if it was not one already.
+fn subscribe_to<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, id: &uuid::Uuid, topic: &str) {
+    let mut senders = senders.lock().unwrap();
+    if let Some(subscriber) = senders.get_mut(id) {
+        if let Subscription::Topics(ref mut topics) = subscriber.subscription {
+            topics.insert(topic.to_owned());
+            return;
+        }
+
+        let mut topics = HashSet::new();
+        topics.insert(topic.to_owned());
+        subscriber.subscription = Subscription::Topics(topics);
+    }
+}
+
+/// Removes `topic` from `id`'s topic subscription, if it has one. Has no
+/// effect on [`Subscription::All`] or predicate subscriptions.
+fn unsubscribe_from<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, id: &uuid::Uuid, topic: &str) {
+    let mut senders = senders.lock().unwrap();
+    if let Some(subscriber) = senders.get_mut(id) {
+        if let Subscription::Topics(ref mut topics) = subscriber.subscription {
+            topics.remove(topic);
+        }
+    }
+}
+
+/// Subscribes `id` to every topic, restoring the original
+/// broadcast-to-all behavior for that subscriber.
+fn subscribe_all_in<S, T>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, id: &uuid::Uuid) {
+    let mut senders = senders.lock().unwrap();
+    if let Some(subscriber) = senders.get_mut(id) {
+        subscriber.subscription = Subscription::All;
+    }
+}
 
+/// Subscribes `id` using a predicate evaluated against each published
+/// message, rather than a topic string.
+fn subscribe_with_in<S, T, F>(senders: &Mutex<HashMap<uuid::Uuid, Subscriber<S, T>>>, id: &uuid::Uuid, predicate: F)
+    where F: Fn(&T) -> bool + Send + 'static
+{
+    let mut senders = senders.lock().unwrap();
+    if let Some(subscriber) = senders.get_mut(id) {
+        subscriber.subscription = Subscription::Predicate(Box::new(predicate));
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+/// The per-subscriber sending half, either an unbounded `mpsc::Sender` or a
+/// fixed-capacity `mpsc::SyncSender`, depending on whether the channel was
+/// created with [`new`] or [`new_bounded`].
+enum ChannelSender<T> {
+    Unbounded(mpsc::Sender<T>),
+    Bounded(mpsc::SyncSender<T>),
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T> Clone for ChannelSender<T> {
+    fn clone(&self) -> Self {
+        match *self {
+            ChannelSender::Unbounded(ref sender) => ChannelSender::Unbounded(sender.clone()),
+            ChannelSender::Bounded(ref sender) => ChannelSender::Bounded(sender.clone()),
+        }
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T> ChannelSender<T> {
+    /// Sends a message, blocking if the subscriber's bounded queue is full.
+    fn send(&self, it: T) -> Result<(), mpsc::SendError<T>> {
+        match *self {
+            ChannelSender::Unbounded(ref sender) => sender.send(it),
+            ChannelSender::Bounded(ref sender) => sender.send(it),
+        }
+    }
+
+    /// Sends a message without blocking, reporting a full or disconnected
+    /// queue instead of waiting.
+    fn try_send(&self, it: T) -> Result<(), mpsc::TrySendError<T>> {
+        match *self {
+            ChannelSender::Unbounded(ref sender) => {
+                sender.send(it).map_err(|mpsc::SendError(it)| mpsc::TrySendError::Disconnected(it))
+            }
+            ChannelSender::Bounded(ref sender) => sender.try_send(it),
+        }
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T> SubscriberSender<T> for ChannelSender<T> {
+    type SendError = mpsc::SendError<T>;
+    type TrySendError = mpsc::TrySendError<T>;
+
+    fn send(&mut self, it: T) -> Result<(), Self::SendError> {
+        ChannelSender::send(self, it)
+    }
+
+    fn try_send(&mut self, it: T) -> Result<(), Self::TrySendError> {
+        ChannelSender::try_send(self, it)
+    }
+
+    fn is_disconnected(err: &Self::TrySendError) -> bool {
+        matches!(err, mpsc::TrySendError::Disconnected(_))
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+type SubscriberMap<T> = Arc<Mutex<HashMap<uuid::Uuid, Subscriber<ChannelSender<T>, T>>>>;
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
 /// Sending component of a pub/sub channel.
 #[derive(Clone)]
 pub struct Sender<T: Clone> {
-    senders: Arc<Mutex<HashMap<uuid::Uuid, mpsc::Sender<T>>>>,
+    senders: SubscriberMap<T>,
 }
 
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
 /// Receiver component of a pub/sub channel.
 pub struct Receiver<T: Clone> {
     receiver: mpsc::Receiver<T>,
-    senders: Arc<Mutex<HashMap<uuid::Uuid, mpsc::Sender<T>>>>,
+    senders: SubscriberMap<T>,
+    capacity: Option<usize>,
     id: uuid::Uuid,
 }
 
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
 impl<T: Clone> Sender<T> {
-    /// Attempts to broadcast
-    pub fn send(&self, it: T) -> Result<(), mpsc::SendError<T>> {
-        let senders = self.senders.lock().unwrap();
-
-        for (_, sender) in senders.iter() {
-            match sender.send(it.clone()) {
-                Ok(_) => {}
-                Err(err) => return Err(err),
+    /// Broadcasts a message to every subscriber, ignoring subscriptions.
+    /// A disconnected subscriber is pruned and delivery continues to
+    /// everyone else, rather than aborting on the first failure. Blocks on
+    /// any subscriber whose bounded queue (see [`new_bounded`]) is
+    /// currently full, but only that subscriber: the shared subscriber map
+    /// is not held while waiting, so other subscribers still receive the
+    /// message and concurrent `Receiver` housekeeping (subscribe/clone/drop)
+    /// is not stalled by it.
+    pub fn send(&self, it: T) -> BroadcastOutcome {
+        broadcast_to(&self.senders, it)
+    }
+
+    /// Broadcasts a message to every subscriber without blocking, ignoring
+    /// subscriptions, pruning any subscriber found disconnected (a full
+    /// one is reported but kept), and reporting each subscriber's outcome
+    /// individually instead of
+    /// bailing out on the first failure.
+    pub fn try_send(&self, it: T) -> Vec<(uuid::Uuid, Result<(), mpsc::TrySendError<T>>)> {
+        try_send_to(&self.senders, it)
+    }
+
+    /// Delivers a message only to subscribers whose subscription matches
+    /// `topic`. A disconnected matching subscriber is pruned and delivery
+    /// continues to everyone else. Blocks on any matching subscriber whose
+    /// bounded queue is currently full, but, as with [`Sender::send`], only
+    /// that subscriber.
+    pub fn publish(&self, topic: &str, it: T) -> BroadcastOutcome {
+        publish_to(&self.senders, topic, it)
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T: Clone> Receiver<T> {
+    /// Receives a single message. Blocks until a message is available.
+    pub fn recv(&self) -> Result<T, mpsc::RecvError> {
+        self.receiver.recv()
+    }
+
+    /// Tries to receive a single message, not blocking if one is not available.
+    pub fn try_recv(&self) -> Result<T, mpsc::TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Creates an iterator that will block waiting for messages.
+    pub fn iter(&self) -> mpsc::Iter<'_, T> {
+        self.receiver.iter()
+    }
+
+    /// Receives a single message, waiting at most `dur` for one to arrive.
+    pub fn recv_timeout(&self, dur: Duration) -> Result<T, mpsc::RecvTimeoutError> {
+        self.receiver.recv_timeout(dur)
+    }
+
+    /// Receives a single message, waiting at most until `deadline` for one
+    /// to arrive.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, mpsc::RecvTimeoutError> {
+        self.receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Creates an iterator that yields messages until `dur` has elapsed,
+    /// for a subscriber that wants to wake periodically without spinning
+    /// on [`Receiver::try_recv`].
+    pub fn iter_timeout(&self, dur: Duration) -> IterTimeout<'_, T> {
+        IterTimeout { receiver: self, deadline: Instant::now() + dur }
+    }
+
+    /// Subscribes to `topic`, in addition to any topics already subscribed
+    /// to. Switches the subscription away from [`Subscription::All`] (or a
+    /// predicate) to an explicit topic set if it was not one already.
+    pub fn subscribe(&self, topic: &str) {
+        subscribe_to(&self.senders, &self.id, topic);
+    }
+
+    /// Removes `topic` from this receiver's topic subscription, if it has
+    /// one. Has no effect on [`Subscription::All`] or predicate subscriptions.
+    pub fn unsubscribe(&self, topic: &str) {
+        unsubscribe_from(&self.senders, &self.id, topic);
+    }
+
+    /// Subscribes to every topic, restoring the original broadcast-to-all
+    /// behavior for this receiver.
+    pub fn subscribe_all(&self) {
+        subscribe_all_in(&self.senders, &self.id);
+    }
+
+    /// Subscribes using a predicate evaluated against each published
+    /// message, rather than a topic string.
+    pub fn subscribe_with<F>(&self, predicate: F)
+        where F: Fn(&T) -> bool + Send + 'static
+    {
+        subscribe_with_in(&self.senders, &self.id, predicate);
+    }
+}
+
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T: Clone> Clone for Receiver<T> {
+    /// Create a new receiver associated with the sender, using the same
+    /// bounded/unbounded queue kind as the receiver it was cloned from and
+    /// defaulting to [`Subscription::All`].
+    fn clone(&self) -> Self {
+        let id = uuid::Uuid::new_v4();
+        let (send, recv) = new_channel_pair(self.capacity);
+
+        {
+            let mut senders = self.senders.lock().unwrap();
+            senders.insert(id, Subscriber { sender: send, subscription: Subscription::All });
+        }
+
+        Receiver {
+            receiver: recv,
+            senders: self.senders.clone(),
+            capacity: self.capacity,
+            id,
+        }
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<T: Clone> Drop for Receiver<T> {
+    /// Remove our sender ID from the sender list.
+    fn drop(&mut self) {
+        let mut senders = self.senders.lock().unwrap();
+        senders.remove(&self.id);
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+/// Iterator returned by [`Receiver::iter_timeout`] that yields messages
+/// until the deadline it was created with has elapsed.
+pub struct IterTimeout<'a, T: Clone + 'a> {
+    receiver: &'a Receiver<T>,
+    deadline: Instant,
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+impl<'a, T: Clone> Iterator for IterTimeout<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv_deadline(self.deadline).ok()
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+fn new_channel_pair<T>(capacity: Option<usize>) -> (ChannelSender<T>, mpsc::Receiver<T>) {
+    match capacity {
+        None => {
+            let (send, recv) = mpsc::channel();
+            (ChannelSender::Unbounded(send), recv)
+        }
+        Some(capacity) => {
+            let (send, recv) = mpsc::sync_channel(capacity);
+            (ChannelSender::Bounded(send), recv)
+        }
+    }
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+/// Create a pub/sub channel with unbounded subscriber queues.
+pub fn new<T: Clone>() -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(None)
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+/// Create a pub/sub channel whose subscribers each have a fixed-capacity
+/// queue. Once a subscriber's queue is full, `Sender::send` blocks until
+/// it has been drained, while `Sender::try_send` reports the full queue
+/// instead of waiting.
+pub fn new_bounded<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(Some(capacity))
+}
+
+#[cfg(not(any(feature = "futures", feature = "crossbeam")))]
+fn new_with_capacity<T: Clone>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let mut senders = HashMap::new();
+
+    let initial_id = uuid::Uuid::new_v4();
+    let (send, recv) = new_channel_pair(capacity);
+
+    senders.insert(initial_id, Subscriber { sender: send, subscription: Subscription::All });
+
+    let senders = Arc::new(Mutex::new(senders));
+
+    (Sender { senders: senders.clone() },
+     Receiver {
+        senders: senders.clone(),
+        capacity,
+        id: initial_id,
+        receiver: recv,
+    })
+}
+
+// --- `futures` backend -------------------------------------------------
+//
+// With the `futures` feature enabled, each subscriber's queue is a
+// `futures_channel::mpsc` pair (`unbounded`, or the fixed-capacity
+// `channel` when created with [`new_bounded`]) instead of a
+// `std::sync::mpsc` pair. `Receiver` implements `Stream` on top of the
+// inner channel, so it can be polled from an executor, and `Sender`
+// implements `Sink`. This backend carries the same topic subscriptions,
+// resilient broadcast and bounded queues as the default backend. The
+// blocking `recv`/`try_recv` methods are kept, with their `std::sync::mpsc`
+// error types, by driving the same `Stream` to completion, and `iter`
+// yields from `recv` until it fails, so code written against the sync API
+// keeps compiling unchanged. `recv_timeout`, `recv_deadline` and
+// `iter_timeout` are not provided by this backend.
+
+#[cfg(feature = "futures")]
+use futures_core::Stream;
+#[cfg(feature = "futures")]
+use futures_sink::Sink;
+#[cfg(feature = "futures")]
+use futures_util::SinkExt;
+#[cfg(feature = "futures")]
+use std::pin::Pin;
+#[cfg(feature = "futures")]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "futures")]
+/// The per-subscriber sending half, either an unbounded
+/// `futures_channel::mpsc::UnboundedSender` or a fixed-capacity
+/// `futures_channel::mpsc::Sender`, depending on whether the channel was
+/// created with [`new`] or [`new_bounded`].
+enum FuturesChannelSender<T> {
+    Unbounded(futures_channel::mpsc::UnboundedSender<T>),
+    Bounded(futures_channel::mpsc::Sender<T>),
+}
+
+#[cfg(feature = "futures")]
+impl<T> Clone for FuturesChannelSender<T> {
+    // `#[derive(Clone)]` would add a spurious `T: Clone` bound: both
+    // underlying sender types are `Clone` regardless of `T`, since cloning
+    // a channel sender just yields another handle to the same channel.
+    fn clone(&self) -> Self {
+        match *self {
+            FuturesChannelSender::Unbounded(ref sender) => FuturesChannelSender::Unbounded(sender.clone()),
+            FuturesChannelSender::Bounded(ref sender) => FuturesChannelSender::Bounded(sender.clone()),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> FuturesChannelSender<T> {
+    /// Sends a message, blocking the current thread if the subscriber's
+    /// bounded queue is full. Only used by the synchronous `Sender::send`
+    /// and `Sender::publish`; the async `Sender::broadcast` uses
+    /// [`FuturesChannelSender::send_async`] and the `Sink` impl uses
+    /// [`FuturesChannelSender::try_send`] instead, since `block_on` here
+    /// would panic if called from inside an already-running executor.
+    fn send(&mut self, it: T) -> Result<(), futures_channel::mpsc::SendError> {
+        match *self {
+            FuturesChannelSender::Unbounded(ref sender) => {
+                sender.unbounded_send(it).map_err(|err| err.into_send_error())
+            }
+            FuturesChannelSender::Bounded(ref mut sender) => {
+                futures_executor::block_on(sender.send(it))
+            }
+        }
+    }
+
+    /// Sends a message without blocking, reporting a full or disconnected
+    /// queue instead of waiting.
+    fn try_send(&mut self, it: T) -> Result<(), futures_channel::mpsc::TrySendError<T>> {
+        match *self {
+            FuturesChannelSender::Unbounded(ref sender) => sender.unbounded_send(it),
+            FuturesChannelSender::Bounded(ref mut sender) => sender.try_send(it),
+        }
+    }
+
+    /// Async counterpart of [`FuturesChannelSender::send`]: awaits the
+    /// subscriber's `Sink` readiness instead of blocking the thread, so it
+    /// is safe to call from a task running on an executor.
+    async fn send_async(&mut self, it: T) -> Result<(), futures_channel::mpsc::SendError> {
+        match *self {
+            FuturesChannelSender::Unbounded(ref sender) => {
+                sender.unbounded_send(it).map_err(|err| err.into_send_error())
+            }
+            FuturesChannelSender::Bounded(ref mut sender) => sender.send(it).await,
+        }
+    }
+
+    /// Polls whether this subscriber's queue has room for another message
+    /// without blocking, registering `cx`'s waker if not. Always ready for
+    /// an unbounded queue.
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), futures_channel::mpsc::SendError>> {
+        match *self {
+            FuturesChannelSender::Unbounded(_) => Poll::Ready(Ok(())),
+            FuturesChannelSender::Bounded(ref mut sender) => Sink::poll_ready(Pin::new(sender), cx),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> SubscriberSender<T> for FuturesChannelSender<T> {
+    type SendError = futures_channel::mpsc::SendError;
+    type TrySendError = futures_channel::mpsc::TrySendError<T>;
+
+    fn send(&mut self, it: T) -> Result<(), Self::SendError> {
+        FuturesChannelSender::send(self, it)
+    }
+
+    fn try_send(&mut self, it: T) -> Result<(), Self::TrySendError> {
+        FuturesChannelSender::try_send(self, it)
+    }
+
+    fn is_disconnected(err: &Self::TrySendError) -> bool {
+        err.is_disconnected()
+    }
+}
+
+#[cfg(feature = "futures")]
+type SubscriberMap<T> = Arc<Mutex<HashMap<uuid::Uuid, Subscriber<FuturesChannelSender<T>, T>>>>;
+
+#[cfg(feature = "futures")]
+/// Sending component of a pub/sub channel.
+#[derive(Clone)]
+pub struct Sender<T: Clone> {
+    senders: SubscriberMap<T>,
+}
+
+#[cfg(feature = "futures")]
+enum FuturesChannelReceiver<T> {
+    Unbounded(futures_channel::mpsc::UnboundedReceiver<T>),
+    Bounded(futures_channel::mpsc::Receiver<T>),
+}
+
+#[cfg(feature = "futures")]
+impl<T> FuturesChannelReceiver<T> {
+    fn try_recv_item(&mut self) -> Result<T, futures_channel::mpsc::TryRecvError> {
+        match *self {
+            FuturesChannelReceiver::Unbounded(ref mut receiver) => receiver.try_recv(),
+            FuturesChannelReceiver::Bounded(ref mut receiver) => receiver.try_recv(),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> Stream for FuturesChannelReceiver<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match *self {
+            FuturesChannelReceiver::Unbounded(ref mut receiver) => Pin::new(receiver).poll_next(cx),
+            FuturesChannelReceiver::Bounded(ref mut receiver) => Pin::new(receiver).poll_next(cx),
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+/// Receiver component of a pub/sub channel.
+pub struct Receiver<T: Clone> {
+    receiver: Mutex<FuturesChannelReceiver<T>>,
+    senders: SubscriberMap<T>,
+    capacity: Option<usize>,
+    id: uuid::Uuid,
+}
+
+#[cfg(feature = "futures")]
+impl<T: Clone> Sender<T> {
+    /// Broadcasts a message to every subscriber, ignoring subscriptions.
+    /// A disconnected subscriber is pruned and delivery continues to
+    /// everyone else, rather than aborting on the first failure. Blocks on
+    /// any subscriber whose bounded queue (see [`new_bounded`]) is
+    /// currently full, but only that subscriber: the shared subscriber map
+    /// is not held while waiting, so other subscribers still receive the
+    /// message and concurrent `Receiver` housekeeping (subscribe/clone/drop)
+    /// is not stalled by it. Prefer [`Sender::broadcast`] from async code.
+    pub fn send(&self, it: T) -> BroadcastOutcome {
+        broadcast_to(&self.senders, it)
+    }
+
+    /// Broadcasts a message to every subscriber without blocking, ignoring
+    /// subscriptions, pruning any subscriber found disconnected (a full
+    /// one is reported but kept), and reporting each subscriber's outcome
+    /// individually instead of
+    /// bailing out on the first failure.
+    pub fn try_send(&self, it: T) -> Vec<(uuid::Uuid, Result<(), futures_channel::mpsc::TrySendError<T>>)> {
+        try_send_to(&self.senders, it)
+    }
+
+    /// Delivers a message only to subscribers whose subscription matches
+    /// `topic`. A disconnected matching subscriber is pruned and delivery
+    /// continues to everyone else. Blocks on any matching subscriber whose
+    /// bounded queue is currently full, but, as with [`Sender::send`], only
+    /// that subscriber.
+    pub fn publish(&self, topic: &str, it: T) -> BroadcastOutcome {
+        publish_to(&self.senders, topic, it)
+    }
+
+    /// Broadcasts a message to every subscriber, awaiting each one's bounded
+    /// queue (see [`new_bounded`]) as needed instead of blocking the
+    /// thread, so this is safe to call from a task already running on an
+    /// executor. A disconnected subscriber is pruned the same way as
+    /// [`Sender::send`].
+    pub async fn broadcast(&self, it: T) -> BroadcastOutcome {
+        // The subscriber's channel handle is cloned out and the lock
+        // released before awaiting its readiness, so one subscriber's full
+        // queue suspends only this broadcast, not delivery to the others or
+        // any concurrent subscribe/unsubscribe/clone/drop on this channel.
+        let targets: Vec<(uuid::Uuid, FuturesChannelSender<T>)> = {
+            let senders = self.senders.lock().unwrap();
+            senders.iter().map(|(id, subscriber)| (*id, subscriber.sender.clone())).collect()
+        };
+
+        let mut outcome = BroadcastOutcome { delivered: 0, dropped: Vec::new() };
+
+        for (id, mut sender) in targets {
+            match sender.send_async(it.clone()).await {
+                Ok(_) => outcome.delivered += 1,
+                Err(_) => outcome.dropped.push(id),
+            }
+        }
+
+        if !outcome.dropped.is_empty() {
+            let mut senders = self.senders.lock().unwrap();
+            for id in &outcome.dropped {
+                senders.remove(id);
+            }
+        }
+
+        outcome
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T: Clone> Sink<T> for Sender<T> {
+    type Error = std::convert::Infallible;
+
+    /// Polls every current subscriber's bounded queue (if any) for room,
+    /// registering `cx`'s waker on whichever isn't ready yet, instead of
+    /// always reporting ready and blocking inside `start_send`.
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let mut targets: Vec<FuturesChannelSender<T>> = {
+            let senders = self.senders.lock().unwrap();
+            senders.values().map(|subscriber| subscriber.sender.clone()).collect()
+        };
+
+        for sender in targets.iter_mut() {
+            if sender.poll_ready(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Fans `item` out with a non-blocking `try_send` to every subscriber.
+    /// `poll_ready` having just reported every queue as having room is what
+    /// makes this safe to do without blocking, per the `Sink` contract; a
+    /// message lost to a race against a subscriber that filled up in
+    /// between the two calls is no worse than `broadcast`/`send` pruning a
+    /// disconnected one.
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        let targets: Vec<(uuid::Uuid, FuturesChannelSender<T>)> = {
+            let senders = self.senders.lock().unwrap();
+            senders.iter().map(|(id, subscriber)| (*id, subscriber.sender.clone())).collect()
+        };
+
+        let mut dead = Vec::new();
+        for (id, mut sender) in targets {
+            if let Err(err) = sender.try_send(item.clone()) {
+                if err.is_disconnected() {
+                    dead.push(id);
+                }
+            }
+        }
+
+        if !dead.is_empty() {
+            let mut senders = self.senders.lock().unwrap();
+            for id in dead {
+                senders.remove(&id);
             }
         }
 
         Ok(())
     }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
 }
 
+#[cfg(feature = "futures")]
 impl<T: Clone> Receiver<T> {
     /// Receives a single message. Blocks until a message is available.
     pub fn recv(&self) -> Result<T, mpsc::RecvError> {
-        self.receiver.recv()
+        let mut receiver = self.receiver.lock().unwrap();
+        futures_executor::block_on(futures_util::StreamExt::next(&mut *receiver))
+            .ok_or(mpsc::RecvError)
     }
 
     /// Tries to receive a single message, not blocking if one is not available.
     pub fn try_recv(&self) -> Result<T, mpsc::TryRecvError> {
+        let mut receiver = self.receiver.lock().unwrap();
+        match receiver.try_recv_item() {
+            Ok(it) => Ok(it),
+            Err(ref err) if err.is_closed() => Err(mpsc::TryRecvError::Disconnected),
+            Err(_) => Err(mpsc::TryRecvError::Empty),
+        }
+    }
+
+    /// Creates an iterator that will block waiting for messages.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(move || self.recv().ok())
+    }
+
+    /// Subscribes to `topic`, in addition to any topics already subscribed
+    /// to. Switches the subscription away from [`Subscription::All`] (or a
+    /// predicate) to an explicit topic set if it was not one already.
+    pub fn subscribe(&self, topic: &str) {
+        subscribe_to(&self.senders, &self.id, topic);
+    }
+
+    /// Removes `topic` from this receiver's topic subscription, if it has
+    /// one. Has no effect on [`Subscription::All`] or predicate subscriptions.
+    pub fn unsubscribe(&self, topic: &str) {
+        unsubscribe_from(&self.senders, &self.id, topic);
+    }
+
+    /// Subscribes to every topic, restoring the original broadcast-to-all
+    /// behavior for this receiver.
+    pub fn subscribe_all(&self) {
+        subscribe_all_in(&self.senders, &self.id);
+    }
+
+    /// Subscribes using a predicate evaluated against each published
+    /// message, rather than a topic string.
+    pub fn subscribe_with<F>(&self, predicate: F)
+        where F: Fn(&T) -> bool + Send + 'static
+    {
+        subscribe_with_in(&self.senders, &self.id, predicate);
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T: Clone> Stream for Receiver<T> {
+    type Item = T;
+
+    /// Polls for the next message, delegating to the inner channel so
+    /// the awaiting task's waker is registered and woken on send.
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        let mut receiver = this.receiver.lock().unwrap();
+        Pin::new(&mut *receiver).poll_next(cx)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T: Clone> Clone for Receiver<T> {
+    /// Create a new receiver associated with the sender, using the same
+    /// bounded/unbounded queue kind as the receiver it was cloned from and
+    /// defaulting to [`Subscription::All`].
+    fn clone(&self) -> Self {
+        let id = uuid::Uuid::new_v4();
+        let (send, recv) = new_futures_pair(self.capacity);
+
+        {
+            let mut senders = self.senders.lock().unwrap();
+            senders.insert(id, Subscriber { sender: send, subscription: Subscription::All });
+        }
+
+        Receiver {
+            receiver: Mutex::new(recv),
+            senders: self.senders.clone(),
+            capacity: self.capacity,
+            id,
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T: Clone> Drop for Receiver<T> {
+    /// Remove our sender ID from the sender list.
+    fn drop(&mut self) {
+        let mut senders = self.senders.lock().unwrap();
+        senders.remove(&self.id);
+    }
+}
+
+#[cfg(feature = "futures")]
+fn new_futures_pair<T>(capacity: Option<usize>) -> (FuturesChannelSender<T>, FuturesChannelReceiver<T>) {
+    match capacity {
+        None => {
+            let (send, recv) = futures_channel::mpsc::unbounded();
+            (FuturesChannelSender::Unbounded(send), FuturesChannelReceiver::Unbounded(recv))
+        }
+        Some(capacity) => {
+            let (send, recv) = futures_channel::mpsc::channel(capacity);
+            (FuturesChannelSender::Bounded(send), FuturesChannelReceiver::Bounded(recv))
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+/// Create a pub/sub channel with unbounded subscriber queues.
+pub fn new<T: Clone>() -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(None)
+}
+
+#[cfg(feature = "futures")]
+/// Create a pub/sub channel whose subscribers each have a fixed-capacity
+/// queue. Once a subscriber's queue is full, `Sender::send` blocks until
+/// it has been drained, while `Sender::try_send` reports the full queue
+/// instead of waiting.
+pub fn new_bounded<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(Some(capacity))
+}
+
+#[cfg(feature = "futures")]
+fn new_with_capacity<T: Clone>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
+    let mut senders = HashMap::new();
+
+    let initial_id = uuid::Uuid::new_v4();
+    let (send, recv) = new_futures_pair(capacity);
+
+    senders.insert(initial_id, Subscriber { sender: send, subscription: Subscription::All });
+
+    let senders = Arc::new(Mutex::new(senders));
+
+    (Sender { senders: senders.clone() },
+     Receiver {
+        senders: senders.clone(),
+        capacity,
+        id: initial_id,
+        receiver: Mutex::new(recv),
+    })
+}
+
+// --- `crossbeam` backend ------------------------------------------------
+//
+// With the `crossbeam` feature enabled (and `futures` disabled), each
+// subscriber's queue is a `crossbeam_channel` instead of a
+// `std::sync::mpsc` one. Unlike `std::sync::mpsc`, a single
+// `crossbeam_channel::Sender`/`Receiver` pair already covers both the
+// bounded and unbounded cases, so no `ChannelSender` enum is needed here.
+// Otherwise this mirrors the default backend exactly: the same topic
+// subscriptions, the same `BroadcastOutcome`-pruning broadcast that does
+// not abort on the first disconnected subscriber, and the same
+// `recv_timeout`/`recv_deadline`/`iter_timeout`. `Receiver::select_handle`
+// additionally exposes the inner `crossbeam_channel::Receiver` so several
+// subscribers can be registered in one `crossbeam_channel::Select` and the
+// caller can block on whichever fires first, fanning in from multiple
+// independent pub/sub channels without a thread per subscriber.
+
+#[cfg(feature = "crossbeam")]
+extern crate crossbeam_channel;
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+impl<T> SubscriberSender<T> for crossbeam_channel::Sender<T> {
+    type SendError = crossbeam_channel::SendError<T>;
+    type TrySendError = crossbeam_channel::TrySendError<T>;
+
+    fn send(&mut self, it: T) -> Result<(), Self::SendError> {
+        crossbeam_channel::Sender::send(self, it)
+    }
+
+    fn try_send(&mut self, it: T) -> Result<(), Self::TrySendError> {
+        crossbeam_channel::Sender::try_send(self, it)
+    }
+
+    fn is_disconnected(err: &Self::TrySendError) -> bool {
+        matches!(err, crossbeam_channel::TrySendError::Disconnected(_))
+    }
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+type SubscriberMap<T> = Arc<Mutex<HashMap<uuid::Uuid, Subscriber<crossbeam_channel::Sender<T>, T>>>>;
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+/// Sending component of a pub/sub channel.
+#[derive(Clone)]
+pub struct Sender<T: Clone> {
+    senders: SubscriberMap<T>,
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+/// Receiver component of a pub/sub channel.
+pub struct Receiver<T: Clone> {
+    receiver: crossbeam_channel::Receiver<T>,
+    senders: SubscriberMap<T>,
+    capacity: Option<usize>,
+    id: uuid::Uuid,
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+impl<T: Clone> Sender<T> {
+    /// Broadcasts a message to every subscriber, ignoring subscriptions.
+    /// A disconnected subscriber is pruned and delivery continues to
+    /// everyone else, rather than aborting on the first failure. Blocks on
+    /// any subscriber whose bounded queue (see [`new_bounded`]) is
+    /// currently full, but only that subscriber: the shared subscriber map
+    /// is not held while waiting, so other subscribers still receive the
+    /// message and concurrent `Receiver` housekeeping (subscribe/clone/drop)
+    /// is not stalled by it.
+    pub fn send(&self, it: T) -> BroadcastOutcome {
+        broadcast_to(&self.senders, it)
+    }
+
+    /// Broadcasts a message to every subscriber without blocking, ignoring
+    /// subscriptions, pruning any subscriber found disconnected (a full
+    /// one is reported but kept), and reporting each subscriber's outcome
+    /// individually instead of
+    /// bailing out on the first failure.
+    pub fn try_send(&self, it: T) -> Vec<(uuid::Uuid, Result<(), crossbeam_channel::TrySendError<T>>)> {
+        try_send_to(&self.senders, it)
+    }
+
+    /// Delivers a message only to subscribers whose subscription matches
+    /// `topic`. A disconnected matching subscriber is pruned and delivery
+    /// continues to everyone else. Blocks on any matching subscriber whose
+    /// bounded queue is currently full, but, as with [`Sender::send`], only
+    /// that subscriber.
+    pub fn publish(&self, topic: &str, it: T) -> BroadcastOutcome {
+        publish_to(&self.senders, topic, it)
+    }
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+impl<T: Clone> Receiver<T> {
+    /// Receives a single message. Blocks until a message is available.
+    pub fn recv(&self) -> Result<T, crossbeam_channel::RecvError> {
+        self.receiver.recv()
+    }
+
+    /// Tries to receive a single message, not blocking if one is not available.
+    pub fn try_recv(&self) -> Result<T, crossbeam_channel::TryRecvError> {
         self.receiver.try_recv()
     }
 
     /// Creates an iterator that will block waiting for messages.
-    pub fn iter(&self) -> mpsc::Iter<T> {
+    pub fn iter(&self) -> crossbeam_channel::Iter<'_, T> {
         self.receiver.iter()
     }
-}
 
+    /// Receives a single message, waiting at most `dur` for one to arrive.
+    pub fn recv_timeout(&self, dur: Duration) -> Result<T, crossbeam_channel::RecvTimeoutError> {
+        self.receiver.recv_timeout(dur)
+    }
+
+    /// Receives a single message, waiting at most until `deadline` for one
+    /// to arrive.
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, crossbeam_channel::RecvTimeoutError> {
+        self.receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Creates an iterator that yields messages until `dur` has elapsed,
+    /// for a subscriber that wants to wake periodically without spinning
+    /// on [`Receiver::try_recv`].
+    pub fn iter_timeout(&self, dur: Duration) -> IterTimeout<'_, T> {
+        IterTimeout { receiver: self, deadline: Instant::now() + dur }
+    }
+
+    /// Returns the inner `crossbeam_channel::Receiver`, so this subscriber
+    /// can be registered alongside others in a single
+    /// `crossbeam_channel::Select` and woken whichever fires first.
+    pub fn select_handle(&self) -> &crossbeam_channel::Receiver<T> {
+        &self.receiver
+    }
 
+    /// Subscribes to `topic`, in addition to any topics already subscribed
+    /// to. Switches the subscription away from [`Subscription::All`] (or a
+    /// predicate) to an explicit topic set if it was not one already.
+    pub fn subscribe(&self, topic: &str) {
+        subscribe_to(&self.senders, &self.id, topic)
+    }
+
+    /// Removes `topic` from this receiver's topic subscription, if it has
+    /// one. Has no effect on [`Subscription::All`] or predicate subscriptions.
+    pub fn unsubscribe(&self, topic: &str) {
+        unsubscribe_from(&self.senders, &self.id, topic)
+    }
+
+    /// Subscribes to every topic, restoring the original broadcast-to-all
+    /// behavior for this receiver.
+    pub fn subscribe_all(&self) {
+        subscribe_all_in(&self.senders, &self.id)
+    }
+
+    /// Subscribes using a predicate evaluated against each published
+    /// message, rather than a topic string.
+    pub fn subscribe_with<F>(&self, predicate: F)
+        where F: Fn(&T) -> bool + Send + 'static
+    {
+        subscribe_with_in(&self.senders, &self.id, predicate)
+    }
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
 impl<T: Clone> Clone for Receiver<T> {
-    /// Create a new receiver associated with the sender.
+    /// Create a new receiver associated with the sender, using the same
+    /// bounded/unbounded queue kind as the receiver it was cloned from and
+    /// defaulting to [`Subscription::All`].
     fn clone(&self) -> Self {
         let id = uuid::Uuid::new_v4();
-        let (send, recv) = mpsc::channel();
+        let (send, recv) = new_crossbeam_pair(self.capacity);
 
         {
             let mut senders = self.senders.lock().unwrap();
-            senders.insert(id, send);
+            senders.insert(id, Subscriber { sender: send, subscription: Subscription::All });
         }
 
         Receiver {
             receiver: recv,
             senders: self.senders.clone(),
-            id: id,
+            capacity: self.capacity,
+            id,
         }
     }
 }
 
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
 impl<T: Clone> Drop for Receiver<T> {
     /// Remove our sender ID from the sender list.
     fn drop(&mut self) {
@@ -154,20 +1251,61 @@ impl<T: Clone> Drop for Receiver<T> {
     }
 }
 
-/// Create a pub/sub channel
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+/// Iterator returned by [`Receiver::iter_timeout`] that yields messages
+/// until the deadline it was created with has elapsed.
+pub struct IterTimeout<'a, T: Clone + 'a> {
+    receiver: &'a Receiver<T>,
+    deadline: Instant,
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+impl<'a, T: Clone> Iterator for IterTimeout<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv_deadline(self.deadline).ok()
+    }
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+fn new_crossbeam_pair<T>(capacity: Option<usize>) -> (crossbeam_channel::Sender<T>, crossbeam_channel::Receiver<T>) {
+    match capacity {
+        None => crossbeam_channel::unbounded(),
+        Some(capacity) => crossbeam_channel::bounded(capacity),
+    }
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+/// Create a pub/sub channel with unbounded subscriber queues.
 pub fn new<T: Clone>() -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(None)
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+/// Create a pub/sub channel whose subscribers each have a fixed-capacity
+/// queue. Once a subscriber's queue is full, `Sender::send` blocks until
+/// it has been drained, while `Sender::try_send` reports the full queue
+/// instead of waiting.
+pub fn new_bounded<T: Clone>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    new_with_capacity(Some(capacity))
+}
+
+#[cfg(all(feature = "crossbeam", not(feature = "futures")))]
+fn new_with_capacity<T: Clone>(capacity: Option<usize>) -> (Sender<T>, Receiver<T>) {
     let mut senders = HashMap::new();
 
     let initial_id = uuid::Uuid::new_v4();
-    let (send, recv) = mpsc::channel();
+    let (send, recv) = new_crossbeam_pair(capacity);
 
-    senders.insert(initial_id, send);
+    senders.insert(initial_id, Subscriber { sender: send, subscription: Subscription::All });
 
     let senders = Arc::new(Mutex::new(senders));
 
     (Sender { senders: senders.clone() },
      Receiver {
         senders: senders.clone(),
+        capacity,
         id: initial_id,
         receiver: recv,
     })
@@ -176,10 +1314,8 @@ pub fn new<T: Clone>() -> (Sender<T>, Receiver<T>) {
 #[cfg(test)]
 extern crate env_logger;
 
-#[cfg(test)]
+#[cfg(all(test, not(any(feature = "futures", feature = "crossbeam"))))]
 mod tests {
-    use std;
-
     use super::*;
 
     fn pre() {
@@ -203,7 +1339,7 @@ mod tests {
             let recv = recv.clone();
             let received = received.clone();
             std::thread::spawn(move || {
-                while let Ok(_) = recv.recv() {
+                while recv.recv().is_ok() {
                     received.fetch_add(1, Ordering::AcqRel);
                 }
             });
@@ -215,10 +1351,247 @@ mod tests {
         for _ in 0..pulses {
             accum += 1;
             debug!("pulse {}", accum);
-            send.send(accum).unwrap();
+            send.send(accum);
         }
 
         std::thread::sleep(std::time::Duration::from_millis(75));
         assert_eq!(received.load(Ordering::Acquire), threads * pulses);
     }
+
+    #[test]
+    fn bounded_queue_blocks_sender_until_drained() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let (send, recv) = new_bounded(1);
+
+        send.send(1);
+
+        let unblocked = std::sync::Arc::new(AtomicBool::new(false));
+        let unblocked_in_thread = unblocked.clone();
+        let sender = std::thread::spawn(move || {
+            send.send(2);
+            unblocked_in_thread.store(true, Ordering::Release);
+        });
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!unblocked.load(Ordering::Acquire));
+
+        assert_eq!(recv.recv(), Ok(1));
+        sender.join().unwrap();
+        assert!(unblocked.load(Ordering::Acquire));
+        assert_eq!(recv.recv(), Ok(2));
+    }
+
+    #[test]
+    fn full_subscriber_does_not_stall_unrelated_receivers() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        let (send, recv_a) = new_bounded(1);
+        let recv_b = recv_a.clone();
+
+        // Fill both capacity-1 queues so the next send blocks.
+        send.send(1);
+
+        let blocked_sender = std::thread::spawn(move || {
+            send.send(2);
+        });
+
+        // Subscribing and dropping recv_b must complete promptly, not get
+        // stuck behind the blocked send above waiting on a full queue.
+        let unblocked = std::sync::Arc::new(AtomicBool::new(false));
+        let unblocked_in_thread = unblocked.clone();
+        let housekeeping = std::thread::spawn(move || {
+            recv_b.subscribe("topic");
+            drop(recv_b);
+            unblocked_in_thread.store(true, Ordering::Release);
+        });
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(unblocked.load(Ordering::Acquire));
+        housekeeping.join().unwrap();
+
+        assert_eq!(recv_a.recv(), Ok(1));
+        blocked_sender.join().unwrap();
+        assert_eq!(recv_a.recv(), Ok(2));
+    }
+
+    #[test]
+    fn try_send_reports_full_queue_without_blocking() {
+        let (send, recv) = new_bounded(1);
+
+        send.send(1);
+
+        let results = send.try_send(2);
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0].1, Err(mpsc::TrySendError::Full(2))));
+
+        assert_eq!(recv.recv(), Ok(1));
+        let results = send.try_send(3);
+        assert!(results[0].1.is_ok());
+        assert_eq!(recv.recv(), Ok(3));
+    }
+
+    #[test]
+    fn publish_only_reaches_matching_subscribers() {
+        let (send, recv_a) = new();
+        let recv_b = recv_a.clone();
+
+        recv_a.subscribe("sports");
+        recv_b.subscribe("weather");
+
+        send.publish("sports", 1);
+        send.publish("weather", 2);
+
+        assert_eq!(recv_a.try_recv(), Ok(1));
+        assert_eq!(recv_a.try_recv(), Err(mpsc::TryRecvError::Empty));
+        assert_eq!(recv_b.try_recv(), Ok(2));
+        assert_eq!(recv_b.try_recv(), Err(mpsc::TryRecvError::Empty));
+    }
+
+    #[test]
+    fn subscribe_with_predicate_filters_messages() {
+        let (send, recv) = new();
+
+        recv.subscribe_with(|it: &i32| *it % 2 == 0);
+
+        send.publish("ignored-topic", 1);
+        send.publish("ignored-topic", 2);
+
+        assert_eq!(recv.try_recv(), Ok(2));
+        assert_eq!(recv.try_recv(), Err(mpsc::TryRecvError::Empty));
+    }
+
+    #[test]
+    fn broadcast_prunes_disconnected_subscribers_instead_of_aborting() {
+        let (send, recv_a) = new();
+
+        // `Receiver::drop` already deregisters a cleanly dropped receiver, so
+        // to exercise the pruning done by `Sender::send` itself, insert a
+        // subscriber whose channel end is already disconnected directly.
+        let dead_id = uuid::Uuid::new_v4();
+        let (dead_send, dead_recv) = mpsc::channel::<i32>();
+        drop(dead_recv);
+        send.senders.lock().unwrap().insert(dead_id, Subscriber {
+            sender: ChannelSender::Unbounded(dead_send),
+            subscription: Subscription::All,
+        });
+
+        let outcome = send.send(1);
+
+        assert_eq!(outcome.delivered, 1);
+        assert_eq!(outcome.dropped, vec![dead_id]);
+        assert_eq!(recv_a.recv(), Ok(1));
+
+        // The pruned subscriber no longer holds up later broadcasts.
+        let outcome = send.send(2);
+        assert_eq!(outcome.delivered, 1);
+        assert!(outcome.dropped.is_empty());
+    }
+
+    #[test]
+    fn recv_timeout_and_deadline_report_empty_channel() {
+        let (_send, recv) = new::<i32>();
+
+        let start = std::time::Instant::now();
+        assert_eq!(recv.recv_timeout(Duration::from_millis(20)), Err(mpsc::RecvTimeoutError::Timeout));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        let deadline = std::time::Instant::now() + Duration::from_millis(20);
+        assert_eq!(recv.recv_deadline(deadline), Err(mpsc::RecvTimeoutError::Timeout));
+    }
+
+    #[test]
+    fn iter_timeout_yields_available_messages_then_stops() {
+        let (send, recv) = new();
+
+        send.send(1);
+        send.send(2);
+
+        let collected: Vec<_> = recv.iter_timeout(Duration::from_millis(50)).collect();
+        assert_eq!(collected, vec![1, 2]);
+    }
+}
+
+#[cfg(all(test, feature = "futures"))]
+mod futures_tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn stream_yields_broadcast_messages() {
+        let (send, mut recv) = new();
+
+        send.send(1);
+        send.send(2);
+
+        let collected = futures_executor::block_on(async {
+            let mut out = Vec::new();
+            out.push(recv.next().await.unwrap());
+            out.push(recv.next().await.unwrap());
+            out
+        });
+
+        assert_eq!(collected, vec![1, 2]);
+    }
+
+    #[test]
+    fn broadcast_awaits_full_bounded_queue_without_blocking_executor() {
+        use futures_executor::LocalPool;
+        use futures_util::task::LocalSpawnExt;
+
+        let (send, recv) = new_bounded::<i32>(1);
+
+        // Fill the one slot so the next broadcast has to wait for it.
+        send.send(1);
+
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+
+        let send_task = send.clone();
+        let outcome = std::rc::Rc::new(std::cell::RefCell::new(None));
+        let outcome_in_task = outcome.clone();
+        spawner
+            .spawn_local(async move {
+                let result = send_task.broadcast(2).await;
+                *outcome_in_task.borrow_mut() = Some(result);
+            })
+            .unwrap();
+
+        // The only subscriber's queue is still full, so the broadcast can't
+        // complete yet. Driving the pool here must not panic the way a
+        // nested `block_on` would ("cannot execute ... from within another
+        // executor").
+        pool.run_until_stalled();
+        assert!(outcome.borrow().is_none());
+
+        // Draining the queue lets the broadcast finish.
+        assert_eq!(recv.recv(), Ok(1));
+        pool.run_until_stalled();
+
+        let outcome = outcome.borrow_mut().take().unwrap();
+        assert_eq!(outcome.delivered, 1);
+        assert!(outcome.dropped.is_empty());
+        assert_eq!(recv.recv(), Ok(2));
+    }
+}
+
+#[cfg(all(test, feature = "crossbeam", not(feature = "futures")))]
+mod crossbeam_tests {
+    use super::*;
+
+    #[test]
+    fn select_fires_on_whichever_channel_is_ready() {
+        let (send_a, recv_a) = new();
+        let (_send_b, recv_b) = new::<i32>();
+
+        send_a.send(1);
+
+        let mut select = crossbeam_channel::Select::new();
+        select.recv(recv_a.select_handle());
+        select.recv(recv_b.select_handle());
+
+        let oper = select.select();
+        assert_eq!(oper.index(), 0);
+        assert_eq!(oper.recv(recv_a.select_handle()), Ok(1));
+    }
 }